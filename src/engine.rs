@@ -1,6 +1,7 @@
 use std::cell::RefCell;
+use std::collections::HashSet;
 use std::fmt::{Debug, Display};
-use std::ops::{Add, Div, Mul, Sub};
+use std::ops::{Add, Div, Mul, Neg, Sub};
 use std::rc::Rc;
 
 #[derive(Clone)]
@@ -17,10 +18,16 @@ struct ValueData {
 #[derive(Clone)]
 enum Operation {
     Add,
+    Sub,
     Mul,
+    Div,
+    Neg,
     Tanh,
     Exp,
     Pow,
+    Ln,
+    Relu,
+    Sigmoid,
 }
 
 impl Value {
@@ -33,14 +40,18 @@ impl Value {
         })))
     }
 
-    fn get_data(&self) -> f32 {
+    pub fn get_data(&self) -> f32 {
         self.0.as_ref().borrow().data
     }
 
-    fn get_grad(&self) -> f32 {
+    pub fn get_grad(&self) -> f32 {
         self.0.as_ref().borrow().grad
     }
 
+    pub fn set_data(&mut self, data: f32) {
+        self.0.borrow_mut().data = data
+    }
+
     pub fn set_grad(&mut self, grad: f32) {
         self.0.borrow_mut().grad = grad
     }
@@ -71,7 +82,40 @@ impl Value {
             data: self.get_data().exp(),
             grad: 0.0,
             children: vec![self.clone()],
-            op: Some(Operation::Tanh),
+            op: Some(Operation::Exp),
+        })))
+    }
+
+    pub fn relu(&self) -> Self {
+        Self(Rc::new(RefCell::new(ValueData {
+            data: self.get_data().max(0.0),
+            grad: 0.0,
+            children: vec![self.clone()],
+            op: Some(Operation::Relu),
+        })))
+    }
+
+    pub fn sigmoid(&self) -> Self {
+        Self(Rc::new(RefCell::new(ValueData {
+            data: 1.0 / (1.0 + (-self.get_data()).exp()),
+            grad: 0.0,
+            children: vec![self.clone()],
+            op: Some(Operation::Sigmoid),
+        })))
+    }
+
+    pub fn ln(&self) -> Self {
+        assert!(
+            self.get_data() > 0.0,
+            "ln is only defined for strictly positive values, got {}",
+            self.get_data()
+        );
+
+        Self(Rc::new(RefCell::new(ValueData {
+            data: self.get_data().ln(),
+            grad: 0.0,
+            children: vec![self.clone()],
+            op: Some(Operation::Ln),
         })))
     }
 
@@ -84,8 +128,28 @@ impl Value {
         })))
     }
 
+    fn ptr(&self) -> usize {
+        Rc::as_ptr(&self.0) as usize
+    }
+
+    // Post-order DFS over the DAG, deduplicating nodes by pointer identity so
+    // that a node fed into more than one op still appears exactly once, and
+    // always after everything it depends on (its children).
+    fn build_topo(&self, visited: &mut HashSet<usize>, topo: &mut Vec<Value>) {
+        if !visited.insert(self.ptr()) {
+            return;
+        }
+        for child in self.get_children() {
+            child.build_topo(visited, topo);
+        }
+        topo.push(self.clone());
+    }
+
+    // Applies this node's local derivative to each of its children's grad.
+    // Assumes every node that reads `self.get_grad()` here has already had
+    // its own grad fully accumulated, which `backward` guarantees by walking
+    // the topological order in reverse.
     fn update_gradients(&self) {
-        // We need to have the root not gradient set already...
         let mut children = self.get_children();
         match self.get_operation() {
             // a + b = c
@@ -95,6 +159,13 @@ impl Value {
                 children[0].add_grad(self.get_grad());
                 children[1].add_grad(self.get_grad());
             }
+            // a - b = c
+            // dc/da = 1 -> a.grad += c.grad
+            // dc/db = -1 -> b.grad -= c.grad
+            Some(Operation::Sub) => {
+                children[0].add_grad(self.get_grad());
+                children[1].add_grad(-self.get_grad());
+            }
             // a * b = c
             // dc/da = b -> a.grad += b.data * c.grad
             // dc/db = a -> b.grad += a.data * c.grad
@@ -104,15 +175,15 @@ impl Value {
                 children[1].add_grad(data[0].get_data() * self.get_grad());
             }
             // tanh(a) = b
-            // db/da = 1 - tanh(a)**2 = 1 - b**2
+            // db/da = 1 - tanh(a)**2 = 1 - b**2 -> a.grad += (1 - b**2) * b.grad
             Some(Operation::Tanh) => {
-                children[0].add_grad(1.0 - self.get_data().powf(2.0));
+                children[0].add_grad((1.0 - self.get_data().powf(2.0)) * self.get_grad());
             }
             // exp(a) = b
-            // db/da = f'(a) * exp(a) = a.grad * a.data
+            // db/da = exp(a) = b -> a.grad += b.data * b.grad
             Some(Operation::Exp) => children[0].add_grad(self.get_data() * self.get_grad()),
             // a**k = b
-            // db/da = k * a**(k - 1) = k.data * a.data.powf(k.data - 1) * k.grad
+            // db/da = k * a**(k - 1) -> a.grad += k.data * a.data.powf(k.data - 1) * b.grad
             Some(Operation::Pow) => {
                 let x = children[1].clone();
                 let val = children[0].clone();
@@ -120,18 +191,55 @@ impl Value {
                     x.get_data() * val.get_data().powf(x.get_data() - 1.0) * self.get_grad(),
                 )
             }
+            // ln(a) = b
+            // db/da = 1/a -> a.grad += b.grad / a.data
+            Some(Operation::Ln) => {
+                let a = children[0].get_data();
+                children[0].add_grad(self.get_grad() / a);
+            }
+            // a / b = c
+            // dc/da = 1/b -> a.grad += c.grad / b.data
+            // dc/db = -a/b**2 -> b.grad += -a.data * c.grad / b.data**2
+            Some(Operation::Div) => {
+                let a = children[0].get_data();
+                let b = children[1].get_data();
+                children[0].add_grad(self.get_grad() / b);
+                children[1].add_grad(-a * self.get_grad() / (b * b));
+            }
+            // -a = b
+            // db/da = -1 -> a.grad -= b.grad
+            Some(Operation::Neg) => {
+                children[0].add_grad(-self.get_grad());
+            }
+            // relu(a) = b
+            // db/da = 1 if a > 0 else 0 -> a.grad += b.grad if a.data > 0 else 0
+            Some(Operation::Relu) => {
+                let a = children[0].get_data();
+                children[0].add_grad(if a > 0.0 { self.get_grad() } else { 0.0 });
+            }
+            // sigmoid(a) = s
+            // ds/da = s * (1 - s) -> a.grad += s.data * (1 - s.data) * s.grad
+            Some(Operation::Sigmoid) => {
+                let s = self.get_data();
+                children[0].add_grad(s * (1.0 - s) * self.get_grad());
+            }
             None => (),
         }
-
-        for child in children {
-            child.update_gradients()
-        }
     }
 
     pub fn backward(&mut self) {
-        // First gradient always 1.0 (derivate with itself)
+        let mut visited = HashSet::new();
+        let mut topo = Vec::new();
+        self.build_topo(&mut visited, &mut topo);
+
+        // First gradient always 1.0 (derivative with itself)
         self.set_grad(1.0);
-        self.update_gradients();
+
+        // Walk the tape in reverse topological order so each node's grad is
+        // fully accumulated before it propagates to its children.
+        for node in topo.iter().rev() {
+            node.update_gradients();
+        }
     }
 }
 
@@ -190,7 +298,7 @@ impl Sub for Value {
             data: self.get_data() - rhs.get_data(),
             grad: 0.0,
             children: vec![self, rhs],
-            op: Some(Operation::Add),
+            op: Some(Operation::Sub),
         })))
     }
 }
@@ -203,7 +311,7 @@ impl Sub<f32> for Value {
             data: self.get_data() - rhs,
             grad: 0.0,
             children: vec![self, Value::new(rhs)],
-            op: Some(Operation::Add),
+            op: Some(Operation::Sub),
         })))
     }
 }
@@ -215,8 +323,8 @@ impl Sub<Value> for f32 {
         Value(Rc::new(RefCell::new(ValueData {
             data: self - rhs.get_data(),
             grad: 0.0,
-            children: vec![rhs, Value::new(self)],
-            op: Some(Operation::Add),
+            children: vec![Value::new(self), rhs],
+            op: Some(Operation::Sub),
         })))
     }
 }
@@ -250,7 +358,7 @@ impl Mul<f32> for Value {
             data: self.get_data() * rhs,
             grad: 0.0,
             children: vec![self, Value::new(rhs)],
-            op: Some(Operation::Add),
+            op: Some(Operation::Mul),
         })))
     }
 }
@@ -263,7 +371,7 @@ impl Mul<Value> for f32 {
             data: rhs.get_data() * self,
             grad: 0.0,
             children: vec![rhs, Value::new(self)],
-            op: Some(Operation::Add),
+            op: Some(Operation::Mul),
         })))
     }
 }
@@ -284,7 +392,7 @@ impl Div for Value {
             data: self.get_data() / rhs.get_data(),
             grad: 0.0,
             children: vec![self, rhs],
-            op: Some(Operation::Mul),
+            op: Some(Operation::Div),
         })))
     }
 }
@@ -297,7 +405,7 @@ impl Div<f32> for Value {
             data: self.get_data() / rhs,
             grad: 0.0,
             children: vec![self, Value::new(rhs)],
-            op: Some(Operation::Add),
+            op: Some(Operation::Div),
         })))
     }
 }
@@ -307,10 +415,10 @@ impl Div<Value> for f32 {
 
     fn div(self, rhs: Value) -> Self::Output {
         Value(Rc::new(RefCell::new(ValueData {
-            data: rhs.get_data() / self,
+            data: self / rhs.get_data(),
             grad: 0.0,
-            children: vec![rhs, Value::new(self)],
-            op: Some(Operation::Add),
+            children: vec![Value::new(self), rhs],
+            op: Some(Operation::Div),
         })))
     }
 }
@@ -323,6 +431,27 @@ impl Div for &Value {
     }
 }
 
+impl Neg for Value {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(Rc::new(RefCell::new(ValueData {
+            data: -self.get_data(),
+            grad: 0.0,
+            children: vec![self],
+            op: Some(Operation::Neg),
+        })))
+    }
+}
+
+impl Neg for &Value {
+    type Output = Value;
+
+    fn neg(self) -> Self::Output {
+        -self.to_owned()
+    }
+}
+
 impl Display for Value {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "[Value={}, Grad={}]", self.get_data(), self.get_grad())
@@ -0,0 +1,30 @@
+use crate::engine::Value;
+
+/// Squared error between predictions and targets, summed over the batch
+/// (not divided by the batch size).
+pub fn mse(pred: &[Value], target: &[Value]) -> Value {
+    pred.iter()
+        .zip(target.iter())
+        .map(|(p, t)| (p - t).powf(2.0))
+        .fold(Value::new(0.0), |a, b| a + b)
+}
+
+/// Softmax cross-entropy loss for a single example: builds softmax from the
+/// raw logits (subtracting the max logit first for numerical stability) and
+/// returns the negative log-probability of the target class.
+pub fn softmax_cross_entropy(logits: &[Value], target_idx: usize) -> Value {
+    let max_logit = logits
+        .iter()
+        .map(|l| l.get_data())
+        .fold(f32::MIN, f32::max);
+
+    let exps: Vec<Value> = logits.iter().map(|l| (l.clone() - max_logit).exp()).collect();
+    let sum = exps
+        .iter()
+        .cloned()
+        .fold(Value::new(0.0), |a, b| a + b);
+
+    let target_prob = exps[target_idx].clone() / sum;
+
+    -target_prob.ln()
+}
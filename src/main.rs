@@ -2,10 +2,24 @@ mod engine;
 use engine::Value;
 
 mod network;
-use network::MLP;
+use network::{Activation, MLP};
+
+mod optim;
+use optim::Sgd;
+
+mod loss;
 
 fn main() {
-    let nn = MLP::new(3, &vec![4, 4, 1]);
+    let nn = MLP::new(
+        3,
+        &vec![4, 4, 4, 1],
+        &vec![
+            Activation::Tanh,
+            Activation::Relu,
+            Activation::Sigmoid,
+            Activation::Linear,
+        ],
+    );
 
     let xs = vec![
         vec![Value::new(2.0), Value::new(3.0), Value::new(-1.0)],
@@ -21,7 +35,47 @@ fn main() {
         Value::new(1.0),
     ];
 
-    let ypred: Vec<Vec<Value>> = xs.iter().map(|x| nn.forward(&x)).collect();
+    let mut optimizer = Sgd::new(nn.parameters(), 0.01);
+
+    for epoch in 0..100 {
+        let ypred: Vec<Value> = xs
+            .iter()
+            .map(|x| nn.forward(x).into_iter().next().unwrap())
+            .collect();
+
+        let mut loss = loss::mse(&ypred, &y);
+
+        nn.zero_grad();
+        loss.backward();
+        optimizer.step();
+
+        println!("epoch {epoch}: loss={}", loss.get_data());
+    }
 
+    let ypred: Vec<Vec<Value>> = xs.iter().map(|x| nn.forward(&x)).collect();
     println!("{:?}", ypred);
+
+    let classifier = MLP::new(2, &vec![4, 3], &vec![Activation::Tanh, Activation::Linear]);
+    let xs_cls = vec![
+        vec![Value::new(1.0), Value::new(1.0)],
+        vec![Value::new(-1.0), Value::new(1.0)],
+        vec![Value::new(-1.0), Value::new(-1.0)],
+    ];
+    let labels = vec![0usize, 1, 2];
+
+    let mut cls_optimizer = Sgd::new(classifier.parameters(), 0.05);
+
+    for epoch in 0..200 {
+        let mut total_loss = Value::new(0.0);
+        for (x, &label) in xs_cls.iter().zip(labels.iter()) {
+            let logits = classifier.forward(x);
+            total_loss = total_loss + loss::softmax_cross_entropy(&logits, label);
+        }
+
+        classifier.zero_grad();
+        total_loss.backward();
+        cls_optimizer.step();
+
+        println!("classification epoch {epoch}: loss={}", total_loss.get_data());
+    }
 }
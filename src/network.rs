@@ -1,14 +1,34 @@
 use crate::engine::Value;
 use rand::distributions::{Distribution, Uniform};
 
+#[derive(Debug, Clone, Copy)]
+pub enum Activation {
+    Tanh,
+    Relu,
+    Sigmoid,
+    Linear,
+}
+
+impl Activation {
+    fn apply(&self, x: Value) -> Value {
+        match self {
+            Activation::Tanh => x.tanh(),
+            Activation::Relu => x.relu(),
+            Activation::Sigmoid => x.sigmoid(),
+            Activation::Linear => x,
+        }
+    }
+}
+
 #[derive(Debug)]
 struct Neuron {
     w: Vec<Value>,
     b: Value,
+    activation: Activation,
 }
 
 impl Neuron {
-    fn new(nin: u32) -> Self {
+    fn new(nin: u32, activation: Activation) -> Self {
         let between = Uniform::from(-1_f32..1_f32);
         let mut rng = rand::thread_rng();
 
@@ -19,16 +39,25 @@ impl Neuron {
         Self {
             w,
             b: Value::new(between.sample(&mut rng) as f32),
+            activation,
         }
     }
 
     fn forward(&self, x: &Vec<Value>) -> Value {
-        self.w
+        let out = self
+            .w
             .iter()
             .zip(x.iter())
             .map(|(xi, wi)| xi * wi)
-            .fold(self.b.clone(), |a, b| a + b)
-            .tanh()
+            .fold(self.b.clone(), |a, b| a + b);
+
+        self.activation.apply(out)
+    }
+
+    fn parameters(&self) -> Vec<Value> {
+        let mut params = self.w.clone();
+        params.push(self.b.clone());
+        params
     }
 }
 
@@ -38,15 +67,19 @@ pub struct Layer {
 }
 
 impl Layer {
-    fn new(nin: u32, nout: u32) -> Self {
+    fn new(nin: u32, nout: u32, activation: Activation) -> Self {
         Self {
-            neurons: (0..nout).map(|_| Neuron::new(nin)).collect(),
+            neurons: (0..nout).map(|_| Neuron::new(nin, activation)).collect(),
         }
     }
 
     fn forward(&self, x: &Vec<Value>) -> Vec<Value> {
         self.neurons.iter().map(|n| n.forward(x)).collect()
     }
+
+    fn parameters(&self) -> Vec<Value> {
+        self.neurons.iter().flat_map(|n| n.parameters()).collect()
+    }
 }
 
 #[derive(Debug)]
@@ -55,19 +88,28 @@ pub struct MLP {
 }
 
 impl MLP {
-    pub fn new(nin: u32, nout: &Vec<u32>) -> Self {
+    pub fn new(nin: u32, nout: &Vec<u32>, activations: &Vec<Activation>) -> Self {
         let mut sz = nout.clone();
         sz.insert(0, nin);
 
         Self {
             layers: (0..nout.len())
-                .map(|i| Layer::new(sz[i], sz[i + 1]))
+                .map(|i| Layer::new(sz[i], sz[i + 1], activations[i]))
                 .collect(),
         }
     }
 
     pub fn forward(&self, x: &Vec<Value>) -> Vec<Value> {
-        let activations: Vec<Vec<Value>> = self.layers.iter().map(|l| l.forward(x)).collect();
-        activations[activations.len() - 1].clone()
+        self.layers.iter().fold(x.clone(), |acc, l| l.forward(&acc))
+    }
+
+    pub fn parameters(&self) -> Vec<Value> {
+        self.layers.iter().flat_map(|l| l.parameters()).collect()
+    }
+
+    pub fn zero_grad(&self) {
+        for mut p in self.parameters() {
+            p.set_grad(0.0);
+        }
     }
 }
@@ -0,0 +1,19 @@
+use crate::engine::Value;
+
+pub struct Sgd {
+    params: Vec<Value>,
+    lr: f32,
+}
+
+impl Sgd {
+    pub fn new(params: Vec<Value>, lr: f32) -> Self {
+        Self { params, lr }
+    }
+
+    pub fn step(&mut self) {
+        for p in self.params.iter_mut() {
+            let updated = p.get_data() - self.lr * p.get_grad();
+            p.set_data(updated);
+        }
+    }
+}